@@ -1,22 +1,41 @@
 extern crate jack;
 extern crate jack_sys;
-
-use dbus;
-use dbus::ffidisp::Connection;
+#[cfg(feature = "pulseaudio")]
+extern crate libpulse_binding as pulse;
 
 use crossbeam_channel::Sender;
 use std::io::Read;
 use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
 
+#[cfg(feature = "pulseaudio")]
+use pulse::context::subscribe::{Facility, InterestMaskSet, Operation as SubscribeOperation};
+#[cfg(feature = "pulseaudio")]
+use pulse::context::{Context, FlagSet as ContextFlagSet, State as ContextState};
+#[cfg(feature = "pulseaudio")]
+use pulse::mainloop::standard::{IterateResult, Mainloop};
+#[cfg(feature = "pulseaudio")]
+use pulse::operation::{Operation, State as OperationState};
+#[cfg(feature = "pulseaudio")]
+use pulse::volume::Volume;
+#[cfg(feature = "pulseaudio")]
+use std::cell::RefCell;
+#[cfg(feature = "pulseaudio")]
+use std::rc::Rc;
+
 use crate::blocks::{Block, ConfigBlock};
 use crate::config::{Config};
 use crate::errors::*;
+use crate::formatting::FormatTemplate;
+use crate::input::{I3BarEvent, MouseButton};
 use crate::scheduler::Task;
 use crate::widget::{I3BarWidget, State};
 use crate::widgets::text::TextWidget;
 
+use std::collections::HashMap;
+
 use serde_derive::Deserialize;
 use uuid::Uuid;
 
@@ -30,15 +49,104 @@ trait SoundDevice {
     fn get_info(&mut self) -> Result<()>;
     fn monitor(&mut self, id: String, tx_update_request: Sender<Task>) -> Result<()>;
 
+    fn set_volume(&mut self, delta: i32) -> Result<()>;
+    fn toggle_mute(&mut self) -> Result<()>;
+
+    fn dsp_load(&self) -> f32;
+    fn xruns(&self) -> u32;
+    fn sample_rate(&self) -> u32;
+    fn buffer_size(&self) -> u32;
+}
+
+// Shared between `JackSoundDevice` and its `Notifications` handler, which
+// runs on the JACK notification thread once the client is activated.
+#[derive(Default)]
+struct JackStatus {
+    running: bool,
+    rolling: bool,
+    capturing: bool,
+    dsp_load: f32,
+    xruns: u32,
+    sample_rate: u32,
+    buffer_size: u32,
+}
+
+// Implements `jack::NotificationHandler` so `JackSoundDevice` learns about
+// transport/graph changes straight from the activated client instead of
+// polling dbus or re-opening a client every tick.
+struct Notifications {
+    id: String,
+    tx_update_request: Sender<Task>,
+    status: Arc<Mutex<JackStatus>>,
+}
+
+impl Notifications {
+    fn notify(&self) {
+        let _ = self.tx_update_request.send(Task {
+            id: self.id.clone(),
+            update_time: Instant::now(),
+        });
+    }
+}
+
+impl jack::NotificationHandler for Notifications {
+    fn client_registration(&mut self, _: &jack::Client, _name: &str, _is_registered: bool) {
+        self.notify();
+    }
+
+    fn port_registration(&mut self, client: &jack::Client, _port_id: jack::PortId, _is_registered: bool) {
+        let capturing = client.ports(
+            Some("jack_capture"),
+            None,
+            jack::PortFlags::empty(),
+        ).iter().any(|name| name.starts_with("jack_capture:"));
+        self.status.lock().unwrap().capturing = capturing;
+        self.notify();
+    }
+
+    fn graph_reorder(&mut self, client: &jack::Client) -> jack::Control {
+        let mut pos = jack_sys::Struct__jack_position { ..Default::default() };
+        let rolling = matches!(
+            unsafe { jack_sys::jack_transport_query(client.raw(), &mut pos) },
+            jack_sys::JackTransportRolling
+        );
+        self.status.lock().unwrap().rolling = rolling;
+        self.notify();
+        jack::Control::Continue
+    }
+
+    fn sample_rate(&mut self, _: &jack::Client, srate: jack::Frames) -> jack::Control {
+        self.status.lock().unwrap().sample_rate = srate as u32;
+        self.notify();
+        jack::Control::Continue
+    }
+
+    fn buffer_size(&mut self, _: &jack::Client, sz: jack::Frames) -> jack::Control {
+        self.status.lock().unwrap().buffer_size = sz as u32;
+        self.notify();
+        jack::Control::Continue
+    }
+
+    fn xrun(&mut self, _: &jack::Client) -> jack::Control {
+        self.status.lock().unwrap().xruns += 1;
+        self.notify();
+        jack::Control::Continue
+    }
+
+    fn shutdown(&mut self, _status: jack::ClientStatus, _reason: &str) {
+        self.status.lock().unwrap().running = false;
+        self.notify();
+    }
 }
 
 struct JackSoundDevice {
     name: String,
     volume: u32,
     muted: bool,
-    jack_running: bool,
-    jack_capturing: bool,
-    jack_rolling: bool,
+    status: Arc<Mutex<JackStatus>>,
+    // Kept alive for as long as the block runs: dropping it deactivates the
+    // client and tears down the notification thread.
+    async_client: Option<jack::AsyncClient<Notifications, ()>>,
 }
 
 impl JackSoundDevice {
@@ -47,9 +155,8 @@ impl JackSoundDevice {
             name,
             volume: 0,
             muted: false,
-            jack_running: false,
-            jack_capturing: false,
-            jack_rolling: false,
+            status: Arc::new(Mutex::new(JackStatus::default())),
+            async_client: None,
         };
         sd.get_info()?;
 
@@ -63,15 +170,15 @@ impl SoundDevice for JackSoundDevice {
     }
 
     fn jack_capturing(&self) -> bool {
-        self.jack_capturing
+        self.status.lock().unwrap().capturing
     }
 
     fn jack_rolling(&self) -> bool {
-        self.jack_rolling
+        self.status.lock().unwrap().rolling
     }
 
     fn jack_running(&self) -> bool {
-        self.jack_running
+        self.status.lock().unwrap().running
     }
 
     fn muted(&self) -> bool {
@@ -79,25 +186,10 @@ impl SoundDevice for JackSoundDevice {
     }
 
     fn get_info(&mut self) -> Result<()> {
-        // Create client
-        self.jack_capturing = false;
-        self.jack_running = false;
-        self.jack_rolling = false;
-        let c_res = jack::Client::new("rusty_client", jack::ClientOptions::NO_START_SERVER);
-        match c_res {
-            Ok((client, _status)) => {
-                let mut pos = jack_sys::Struct__jack_position {..Default::default()};
-                self.jack_rolling = match unsafe {jack_sys::jack_transport_query(client.raw(),&mut pos)} {
-                    jack_sys::JackTransportRolling =>true,
-                    _ =>false,
-                };
-                self.jack_running = true;
-                if let Some(_port) = client.port_by_name("jack_capture:input1"){
-                    self.jack_capturing = true;
-                }
-            },
-            _ =>{},
-        };
+        // Port/capture state is kept up to date by the `Notifications`
+        // handler on the activated client (set up in `monitor`); transport
+        // rolling state and DSP load are polled below. Until that client
+        // exists there's simply nothing running yet.
         let output = Command::new("amixer")
             .args(&["get", &self.name])
             .output()
@@ -123,10 +215,27 @@ impl SoundDevice for JackSoundDevice {
 
         self.muted = last.get(1).map(|muted| *muted == "off").unwrap_or(false);
 
+        // DSP load and transport rolling state aren't reliably event-driven
+        // (JACK has no "transport started/stopped" notification, and
+        // `graph_reorder` only fires on port-graph changes), so poll both
+        // straight from the activated client on every refresh.
+        if let Some(async_client) = &self.async_client {
+            let client = async_client.as_client();
+            let load = unsafe { jack_sys::jack_cpu_load(client.raw()) };
+            let mut pos = jack_sys::Struct__jack_position { ..Default::default() };
+            let rolling = matches!(
+                unsafe { jack_sys::jack_transport_query(client.raw(), &mut pos) },
+                jack_sys::JackTransportRolling
+            );
+            let mut status = self.status.lock().unwrap();
+            status.dsp_load = load;
+            status.rolling = rolling;
+        }
+
         Ok(())
     }
 
-    
+
     fn monitor(&mut self, id: String, tx_update_request: Sender<Task>) -> Result<()> {
         
         let id0 = id.clone();
@@ -160,72 +269,359 @@ impl SoundDevice for JackSoundDevice {
             }
         });
 
-        /*
-        let id1 = id.clone();
-        let txur1 = tx_update_request.clone();
-        thread::spawn(move || {
-            // Line-buffer to reduce noise.
-            let mut monitor = Command::new("stdbuf")
-                .args(&["-oL", "pactl", "subscribe"])
-                .stdout(Stdio::piped())
-                .spawn()
-                .expect("Failed to start pactl monitor")
-                .stdout
-                .expect("Failed to pipe pactl monitor output");
+        // Open a single persistent client and activate it with our
+        // notification handler: JACK then calls us back exactly when
+        // client/port/graph state changes, instead of us polling dbus.
+        // Transport state has no notification of its own and is polled in
+        // `get_info`.
+        //
+        // A missing/stopped JACK server is a normal setup for ALSA-only
+        // users (this is also `SoundDriver::Auto`'s fallback device), so we
+        // just leave `running` false and keep reporting ALSA-only info
+        // instead of failing block startup like a real error would.
+        let client = match jack::Client::new("i3status-rust", jack::ClientOptions::NO_START_SERVER) {
+            Ok((client, _status)) => client,
+            Err(_) => return Ok(()),
+        };
 
-            let mut buffer = [0; 1024]; // Should be more than enough.
-            loop {
-                // Block until we get some output. Doesn't really matter what
-                // the output actually is -- these are events -- we just update
-                // the sound information if *something* happens.
-                if monitor.read(&mut buffer).is_ok() {
-                    txur1
-                        .send(Task {
-                            id: id1.clone(),
-                            update_time: Instant::now(),
-                        })
-                        .unwrap();
+        {
+            let mut status = self.status.lock().unwrap();
+            status.running = true;
+            let capturing = client
+                .ports(Some("jack_capture"), None, jack::PortFlags::empty())
+                .iter()
+                .any(|name| name.starts_with("jack_capture:"));
+            status.capturing = capturing;
+            let mut pos = jack_sys::Struct__jack_position { ..Default::default() };
+            status.rolling = matches!(
+                unsafe { jack_sys::jack_transport_query(client.raw(), &mut pos) },
+                jack_sys::JackTransportRolling
+            );
+            status.sample_rate = client.sample_rate() as u32;
+            status.buffer_size = client.buffer_size();
+        }
+
+        let notifications = Notifications {
+            id,
+            tx_update_request,
+            status: self.status.clone(),
+        };
+
+        match client.activate_async(notifications, ()) {
+            Ok(async_client) => self.async_client = Some(async_client),
+            Err(_) => {
+                self.status.lock().unwrap().running = false;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn set_volume(&mut self, delta: i32) -> Result<()> {
+        let step = format!("{}%{}", delta.abs(), if delta >= 0 { "+" } else { "-" });
+        Command::new("amixer")
+            .args(&["set", &self.name, &step])
+            .output()
+            .block_error("sound", "failed to set volume via amixer")?;
+
+        self.get_info()
+    }
+
+    fn toggle_mute(&mut self) -> Result<()> {
+        Command::new("amixer")
+            .args(&["set", &self.name, "toggle"])
+            .output()
+            .block_error("sound", "failed to toggle mute via amixer")?;
+
+        self.get_info()
+    }
+
+    fn dsp_load(&self) -> f32 {
+        self.status.lock().unwrap().dsp_load
+    }
+
+    fn xruns(&self) -> u32 {
+        self.status.lock().unwrap().xruns
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.status.lock().unwrap().sample_rate
+    }
+
+    fn buffer_size(&self) -> u32 {
+        self.status.lock().unwrap().buffer_size
+    }
+}
+
+#[cfg(feature = "pulseaudio")]
+struct PulseSoundDevice {
+    name: String,
+    volume: u32,
+    muted: bool,
+    channels: u8,
+}
+
+#[cfg(feature = "pulseaudio")]
+impl PulseSoundDevice {
+    fn new(name: String) -> Result<Self> {
+        let mut sd = PulseSoundDevice {
+            name,
+            volume: 0,
+            muted: false,
+            channels: 2,
+        };
+        sd.get_info()?;
+
+        Ok(sd)
+    }
+
+    fn connect() -> Result<(Mainloop, Context)> {
+        let mut mainloop = Mainloop::new().block_error("sound", "failed to create pulseaudio mainloop")?;
+        let mut context = Context::new(&mainloop, "i3status-rust")
+            .block_error("sound", "failed to create pulseaudio context")?;
+
+        context
+            .connect(None, ContextFlagSet::NOFLAGS, None)
+            .block_error("sound", "failed to connect to pulseaudio")?;
+
+        loop {
+            match mainloop.iterate(true) {
+                IterateResult::Quit(_) | IterateResult::Err(_) => {
+                    return Err("failed to iterate pulseaudio mainloop".into());
                 }
-                // Don't update too often. Wait 1/4 second, fast enough for
-                // volume button mashing but slow enough to skip event spam.
-                thread::sleep(Duration::new(0, 250_000_000))
+                IterateResult::Success(_) => {}
             }
-        });
+            match context.get_state() {
+                ContextState::Ready => break,
+                ContextState::Failed | ContextState::Terminated => {
+                    return Err("pulseaudio context failed to connect".into());
+                }
+                _ => {}
+            }
+        }
 
-        */
+        Ok((mainloop, context))
+    }
 
-        let id2 = id.clone();
-        let txur2 = tx_update_request.clone();
-        thread::spawn(move || {
-            // First open up a connection to the session bus.
-            let c = Connection::new_session().unwrap();
-
-            // match server started and stopped events
-            c.add_match("interface='org.jackaudio.JackControl',member='ServerStarted'").unwrap();
-            c.add_match("interface='org.jackaudio.JackControl',member='ServerStopped'").unwrap();
-            c.add_match("interface='org.jackaudio.JackControl',member='IsStarted'").unwrap();
-            // also match jack_capture appear/disappear
-            c.add_match("interface='org.jackaudio.JackPatchbay',member='ClientAppeared',arg2='jack_transport'").unwrap();
-            c.add_match("interface='org.jackaudio.JackPatchbay',member='ClientAppeared',arg2='jack_capture'").unwrap();
-            c.add_match("interface='org.jackaudio.JackPatchbay',member='ClientDisappeared',arg2='jack_capture'").unwrap();
+    fn iterate_until_done(mainloop: &mut Mainloop, done: &Rc<RefCell<bool>>) -> Result<()> {
+        while !*done.borrow() {
+            match mainloop.iterate(true) {
+                IterateResult::Quit(_) | IterateResult::Err(_) => {
+                    return Err("failed to iterate pulseaudio mainloop".into());
+                }
+                IterateResult::Success(_) => {}
+            }
+        }
+        Ok(())
+    }
 
-            loop {
+    fn drive_operation<T: ?Sized>(mainloop: &mut Mainloop, op: &Operation<T>) -> Result<()> {
+        loop {
+            match op.get_state() {
+                OperationState::Done => return Ok(()),
+                OperationState::Cancelled => {
+                    return Err("pulseaudio operation was cancelled".into());
+                }
+                OperationState::Running => {}
+            }
+            match mainloop.iterate(true) {
+                IterateResult::Quit(_) | IterateResult::Err(_) => {
+                    return Err("failed to iterate pulseaudio mainloop".into());
+                }
+                IterateResult::Success(_) => {}
+            }
+        }
+    }
 
-                if let Some(_) = c.incoming(1000).next() {
-                    txur2
-                        .send(Task {
-                            id: id2.clone(),
+    // Queries a single sink by name to completion, filling in `volume`,
+    // `muted` and `channels` if it exists. Leaves `volume` untouched
+    // (still `None`) if the sink can't be found.
+    fn fetch_sink_info(
+        mainloop: &mut Mainloop,
+        context: &mut Context,
+        sink_name: &str,
+        volume: &Rc<RefCell<Option<u32>>>,
+        muted: &Rc<RefCell<bool>>,
+        channels: &Rc<RefCell<u8>>,
+    ) -> Result<()> {
+        let done = Rc::new(RefCell::new(false));
+
+        {
+            let volume = volume.clone();
+            let muted = muted.clone();
+            let channels = channels.clone();
+            let done = done.clone();
+            context
+                .introspect()
+                .get_sink_info_by_name(sink_name, move |list| match list {
+                    pulse::callbacks::ListResult::Item(info) => {
+                        let avg = info.volume.avg();
+                        let pct = (avg.0 as f64 / Volume::NORMAL.0 as f64 * 100.0).round() as u32;
+                        *volume.borrow_mut() = Some(pct);
+                        *muted.borrow_mut() = info.mute;
+                        *channels.borrow_mut() = info.volume.len();
+                    }
+                    pulse::callbacks::ListResult::End | pulse::callbacks::ListResult::Error => {
+                        *done.borrow_mut() = true;
+                    }
+                });
+        }
+
+        Self::iterate_until_done(mainloop, &done)
+    }
+}
+
+#[cfg(feature = "pulseaudio")]
+impl SoundDevice for PulseSoundDevice {
+    fn volume(&self) -> u32 {
+        self.volume
+    }
+
+    fn jack_capturing(&self) -> bool {
+        false
+    }
+
+    fn jack_rolling(&self) -> bool {
+        false
+    }
+
+    fn jack_running(&self) -> bool {
+        false
+    }
+
+    fn muted(&self) -> bool {
+        self.muted
+    }
+
+    fn get_info(&mut self) -> Result<()> {
+        let (mut mainloop, mut context) = Self::connect()?;
+
+        let volume = Rc::new(RefCell::new(None));
+        let muted = Rc::new(RefCell::new(false));
+        let channels = Rc::new(RefCell::new(self.channels));
+
+        Self::fetch_sink_info(&mut mainloop, &mut context, &self.name, &volume, &muted, &channels)?;
+
+        // The configured (or default "Master") name didn't resolve to a
+        // sink -- ask the server which sink it actually considers the
+        // default and retry against that before giving up.
+        if volume.borrow().is_none() {
+            let default_sink_name = Rc::new(RefCell::new(None));
+            let done = Rc::new(RefCell::new(false));
+            {
+                let default_sink_name = default_sink_name.clone();
+                let done = done.clone();
+                context.introspect().get_server_info(move |info| {
+                    *default_sink_name.borrow_mut() =
+                        info.default_sink_name.as_ref().map(|s| s.to_string());
+                    *done.borrow_mut() = true;
+                });
+            }
+            Self::iterate_until_done(&mut mainloop, &done)?;
+
+            let default_sink_name = default_sink_name.borrow().clone();
+            if let Some(default_sink_name) = default_sink_name {
+                Self::fetch_sink_info(
+                    &mut mainloop,
+                    &mut context,
+                    &default_sink_name,
+                    &volume,
+                    &muted,
+                    &channels,
+                )?;
+            }
+        }
+
+        context.disconnect();
+
+        self.volume = (*volume.borrow()).block_error("sound", "could not find a pulseaudio sink")?;
+        self.muted = *muted.borrow();
+        self.channels = *channels.borrow();
+
+        Ok(())
+    }
+
+    fn monitor(&mut self, id: String, tx_update_request: Sender<Task>) -> Result<()> {
+        thread::spawn(move || {
+            // Each thread gets its own mainloop/context pair so updates can
+            // be pushed as soon as PulseAudio reports a sink or server change,
+            // instead of polling.
+            let (mut mainloop, mut context) = match PulseSoundDevice::connect() {
+                Ok(pair) => pair,
+                Err(_) => return,
+            };
+
+            context.set_subscribe_callback(Some(Box::new({
+                let id = id.clone();
+                let tx_update_request = tx_update_request.clone();
+                move |facility, _operation: Option<SubscribeOperation>, _index| {
+                    if matches!(facility, Some(Facility::Sink) | Some(Facility::Server)) {
+                        let _ = tx_update_request.send(Task {
+                            id: id.clone(),
                             update_time: Instant::now(),
-                        })
-                        .unwrap();
+                        });
+                    }
                 }
+            })));
 
-                thread::sleep(Duration::new(0, 250_000_000))
+            context.subscribe(InterestMaskSet::SINK | InterestMaskSet::SERVER, |_| {});
+
+            loop {
+                match mainloop.iterate(true) {
+                    IterateResult::Quit(_) | IterateResult::Err(_) => break,
+                    IterateResult::Success(_) => {}
+                }
             }
         });
 
         Ok(())
     }
+
+    fn set_volume(&mut self, delta: i32) -> Result<()> {
+        let (mut mainloop, mut context) = Self::connect()?;
+
+        let target_pct = (self.volume as i32 + delta).max(0) as f64 / 100.0;
+        let target = Volume((Volume::NORMAL.0 as f64 * target_pct).round() as u32);
+        let mut volume = pulse::volume::ChannelVolumes::default();
+        volume.set(self.channels, target);
+
+        let op = context
+            .introspect()
+            .set_sink_volume_by_name(&self.name, &volume, None);
+        Self::drive_operation(&mut mainloop, &op)?;
+        context.disconnect();
+
+        self.get_info()
+    }
+
+    fn toggle_mute(&mut self) -> Result<()> {
+        let (mut mainloop, mut context) = Self::connect()?;
+        let mute = !self.muted;
+
+        let op = context
+            .introspect()
+            .set_sink_mute_by_name(&self.name, mute, None);
+        Self::drive_operation(&mut mainloop, &op)?;
+        context.disconnect();
+
+        self.get_info()
+    }
+
+    fn dsp_load(&self) -> f32 {
+        0.0
+    }
+
+    fn xruns(&self) -> u32 {
+        0
+    }
+
+    fn sample_rate(&self) -> u32 {
+        0
+    }
+
+    fn buffer_size(&self) -> u32 {
+        0
+    }
 }
 
 pub struct Jack {
@@ -233,7 +629,36 @@ pub struct Jack {
     id: String,
     device: Box<dyn SoundDevice>,
     config: Config,
-    show_volume_when_muted: bool,
+    step_width: u32,
+    max_vol: Option<u32>,
+    scrolling: Scrolling,
+    format: FormatTemplate,
+    format_muted: FormatTemplate,
+    dsp_load_warning: f32,
+    dsp_load_critical: f32,
+    last_xruns: u32,
+}
+
+#[derive(Deserialize, Copy, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Scrolling {
+    Natural,
+    Reverse,
+}
+
+impl Default for Scrolling {
+    fn default() -> Self {
+        Scrolling::Natural
+    }
+}
+
+impl Scrolling {
+    fn signed(self, step: i32) -> i32 {
+        match self {
+            Scrolling::Natural => step,
+            Scrolling::Reverse => -step,
+        }
+    }
 }
 
 #[derive(Deserialize, Debug, Default, Clone)]
@@ -247,9 +672,39 @@ pub struct SoundConfig {
     #[serde(default = "SoundConfig::default_name")]
     pub name: Option<String>,
 
-    #[serde(default = "SoundConfig::default_show_volume_when_muted")]
-    pub show_volume_when_muted: bool,
+    /// The steps volume is in/decreased for the selected audio device (When greater than 50 it gets limited to 50)
+    #[serde(default = "SoundConfig::default_step_width")]
+    pub step_width: u32,
+
+    /// Max volume in percent that can be set via scrolling, can be more than 100, None means no limit
+    #[serde(default = "SoundConfig::default_max_vol")]
+    pub max_vol: Option<u32>,
+
+    /// Invert the scrolling direction for volume control
+    #[serde(default = "Scrolling::default")]
+    pub scrolling: Scrolling,
 
+    /// Format string, supports placeholders: {volume} {driver} {transport} {capturing}
+    /// {dsp_load} {xruns} {sample_rate} {buffer_size}
+    #[serde(default = "SoundConfig::default_format")]
+    pub format: FormatTemplate,
+
+    /// Format string used while the device is muted
+    #[serde(default)]
+    pub format_muted: Option<FormatTemplate>,
+
+    /// Deprecated: use `format_muted` instead. Still honored when
+    /// `format_muted` is left unset, so existing configs keep working.
+    #[serde(default)]
+    pub show_volume_when_muted: Option<bool>,
+
+    /// JACK DSP load percentage at which the widget turns to a Warning state
+    #[serde(default = "SoundConfig::default_dsp_load_warning")]
+    pub dsp_load_warning: f32,
+
+    /// JACK DSP load percentage at which the widget turns to a Critical state
+    #[serde(default = "SoundConfig::default_dsp_load_critical")]
+    pub dsp_load_critical: f32,
 }
 
 #[derive(Deserialize, Copy, Clone, Debug)]
@@ -257,6 +712,7 @@ pub struct SoundConfig {
 pub enum SoundDriver {
     Auto,
     Alsa,
+    Pulse,
 }
 
 impl Default for SoundDriver {
@@ -269,8 +725,34 @@ impl SoundConfig {
     fn default_name() -> Option<String> {
         None
     }
-    fn default_show_volume_when_muted() -> bool {
-        false
+    fn default_step_width() -> u32 {
+        5
+    }
+    fn default_max_vol() -> Option<u32> {
+        None
+    }
+    fn default_format() -> FormatTemplate {
+        FormatTemplate::from_string("{driver} {volume}% {capturing}{transport}")
+            .expect("SoundConfig::default_format template is invalid")
+    }
+    // `format_muted` takes priority when set. Otherwise fall back to the
+    // deprecated `show_volume_when_muted` flag so existing configs keep
+    // behaving the way they used to, and finally to icon-only.
+    fn resolve_format_muted(format_muted: Option<FormatTemplate>, show_volume_when_muted: Option<bool>) -> FormatTemplate {
+        format_muted.unwrap_or_else(|| {
+            let template = if show_volume_when_muted.unwrap_or(false) {
+                " {volume}%"
+            } else {
+                ""
+            };
+            FormatTemplate::from_string(template).expect("SoundConfig::resolve_format_muted template is invalid")
+        })
+    }
+    fn default_dsp_load_warning() -> f32 {
+        80.0
+    }
+    fn default_dsp_load_critical() -> f32 {
+        95.0
     }
 }
 
@@ -282,6 +764,33 @@ impl Jack {
         let running = self.device.jack_running();
         let rolling = self.device.jack_rolling();
         let capturing = self.device.jack_capturing();
+        let dsp_load = self.device.dsp_load();
+        let xruns = self.device.xruns();
+
+        // A new xrun since the last refresh means the graph just glitched,
+        // even if the load has since settled back down. Only consumed while
+        // unmuted, so a glitch that happens during a muted stretch still
+        // surfaces once the device is unmuted again.
+        let new_xrun = xruns > self.last_xruns;
+
+        let mut values = HashMap::new();
+        values.insert("volume", format!("{:02}", volume));
+        values.insert("driver", (if running { "JACK" } else { "ALSA" }).to_string());
+        values.insert(
+            "transport",
+            (if running {
+                if rolling { PLAY_ICON } else { STOP_ICON }
+            } else {
+                ""
+            })
+            .to_string(),
+        );
+        values.insert("capturing", (if capturing { REC_ICON } else { "" }).to_string());
+        values.insert("dsp_load", format!("{:.1}", dsp_load));
+        values.insert("xruns", xruns.to_string());
+        values.insert("sample_rate", self.device.sample_rate().to_string());
+        values.insert("buffer_size", self.device.buffer_size().to_string());
+
         if self.device.muted() {
             self.text.set_icon("volume_empty");
             let icon = self
@@ -290,11 +799,8 @@ impl Jack {
                 .get("volume_muted")
                 .block_error("sound", "cannot find icon")?
                 .to_owned();
-            if self.show_volume_when_muted {
-                self.text.set_text(format!("{} {:02}%", icon, volume));
-            } else {
-                self.text.set_text(icon);
-            }
+            self.text
+                .set_text(format!("{}{}", icon, self.format_muted.render(&values)?));
             self.text.set_state(State::Warning);
         } else {
             self.text.set_icon(match volume {
@@ -302,20 +808,28 @@ impl Jack {
                 21..=70 => "volume_half",
                 _ => "volume_full",
             });
-            self.text.set_text(
-                format!(
-                    "{} {:02}% {}{}", 
-                    if running { "JACK"} else { "ALSA"}, 
-                    volume,
-                    if capturing{ REC_ICON} else { ""},
-                    if running {if rolling{ PLAY_ICON} else { STOP_ICON}} else {""}
-                )
-            );
-            self.text.set_state(State::Idle);
+            self.text.set_text(self.format.render(&values)?);
+            self.text.set_state(if new_xrun || dsp_load >= self.dsp_load_critical {
+                State::Critical
+            } else if dsp_load >= self.dsp_load_warning {
+                State::Warning
+            } else {
+                State::Idle
+            });
+            self.last_xruns = xruns;
         }
 
         Ok(())
     }
+
+    fn scroll_volume(&mut self, direction: i32) -> Result<()> {
+        let delta = self.scrolling.signed(self.step_width as i32 * direction);
+        let current = self.device.volume() as i32;
+        let max = self.max_vol.map(|v| v as i32).unwrap_or(i32::MAX);
+        let target = (current + delta).max(0).min(max);
+
+        self.device.set_volume(target - current)
+    }
 }
 
 impl ConfigBlock for Jack {
@@ -328,9 +842,33 @@ impl ConfigBlock for Jack {
     ) -> Result<Self> {
         let id = Uuid::new_v4().to_simple().to_string();
 
-        let device: Box<dyn SoundDevice> =  Box::new(JackSoundDevice::new(
-                block_config.name.unwrap_or_else(|| "Master".into()),
-            )?);
+        let name = block_config.name.clone().unwrap_or_else(|| "Master".into());
+
+        let device: Box<dyn SoundDevice> = match block_config.driver {
+            #[cfg(feature = "pulseaudio")]
+            SoundDriver::Pulse => Box::new(PulseSoundDevice::new(name)?),
+            #[cfg(not(feature = "pulseaudio"))]
+            SoundDriver::Pulse => {
+                return Err(()).block_error(
+                    "sound",
+                    "the pulseaudio driver requires i3status-rust to be built with the `pulseaudio` feature",
+                );
+            }
+            SoundDriver::Alsa => Box::new(JackSoundDevice::new(name)?),
+            SoundDriver::Auto => {
+                #[cfg(feature = "pulseaudio")]
+                {
+                    match PulseSoundDevice::new(name.clone()) {
+                        Ok(device) => Box::new(device),
+                        Err(_) => Box::new(JackSoundDevice::new(name)?),
+                    }
+                }
+                #[cfg(not(feature = "pulseaudio"))]
+                {
+                    Box::new(JackSoundDevice::new(name)?)
+                }
+            }
+        };
 
 
         let mut sound = Self {
@@ -338,7 +876,17 @@ impl ConfigBlock for Jack {
             id: id.clone(),
             device,
             config,
-            show_volume_when_muted: block_config.show_volume_when_muted,
+            step_width: block_config.step_width.min(50),
+            max_vol: block_config.max_vol,
+            scrolling: block_config.scrolling,
+            format: block_config.format,
+            format_muted: SoundConfig::resolve_format_muted(
+                block_config.format_muted,
+                block_config.show_volume_when_muted,
+            ),
+            dsp_load_warning: block_config.dsp_load_warning,
+            dsp_load_critical: block_config.dsp_load_critical,
+            last_xruns: 0,
         };
 
         sound
@@ -358,13 +906,39 @@ const STOP_ICON: &'static str = "  ";
 impl Block for Jack {
     fn update(&mut self) -> Result<Option<Duration>> {
         self.display()?;
-        Ok(None) // The monitor thread will call for updates when needed.
+        // The monitor thread pushes an update whenever JACK/ALSA/Pulse tells
+        // us something changed, but DSP load can creep up with no graph or
+        // port event to trigger a refresh off of -- so while JACK is running
+        // also poll on a short fixed interval to keep load (and the
+        // Warning/Critical state it drives) from going stale.
+        Ok(if self.device.jack_running() {
+            Some(Duration::new(1, 0))
+        } else {
+            None
+        })
     }
 
     fn view(&self) -> Vec<&dyn I3BarWidget> {
         vec![&self.text]
     }
 
+    fn click(&mut self, event: &I3BarEvent) -> Result<()> {
+        match event.button {
+            MouseButton::Left => {
+                self.device.toggle_mute()?;
+            }
+            MouseButton::WheelUp => {
+                self.scroll_volume(1)?;
+            }
+            MouseButton::WheelDown => {
+                self.scroll_volume(-1)?;
+            }
+            _ => {}
+        }
+
+        self.display()
+    }
+
     fn id(&self) -> &str {
         &self.id
     }
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer, Error as DeError, Visitor};
+
+use crate::errors::*;
+
+#[derive(Debug, Clone, PartialEq)]
+enum FormatPart {
+    Literal(String),
+    Placeholder(String),
+}
+
+/// A small `"{name}"`-style template, parsed once at config time and then
+/// rendered on every block update by substituting each placeholder with the
+/// value the block looked up for it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormatTemplate(Vec<FormatPart>);
+
+impl Default for FormatTemplate {
+    fn default() -> Self {
+        FormatTemplate(Vec::new())
+    }
+}
+
+impl FormatTemplate {
+    pub fn from_string(s: &str) -> Result<Self> {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut chars = s.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c == '{' {
+                let mut placeholder = String::new();
+                let mut closed = false;
+                for c in &mut chars {
+                    if c == '}' {
+                        closed = true;
+                        break;
+                    }
+                    placeholder.push(c);
+                }
+                if !closed {
+                    return Err("unclosed '{' in format string".into());
+                }
+                if !literal.is_empty() {
+                    parts.push(FormatPart::Literal(std::mem::take(&mut literal)));
+                }
+                parts.push(FormatPart::Placeholder(placeholder));
+            } else {
+                literal.push(c);
+            }
+        }
+        if !literal.is_empty() {
+            parts.push(FormatPart::Literal(literal));
+        }
+
+        Ok(FormatTemplate(parts))
+    }
+
+    pub fn render(&self, values: &HashMap<&str, String>) -> Result<String> {
+        let mut rendered = String::new();
+        for part in &self.0 {
+            match part {
+                FormatPart::Literal(s) => rendered.push_str(s),
+                FormatPart::Placeholder(key) => {
+                    let value = values
+                        .get(key.as_str())
+                        .block_error("formatting", &format!("unknown placeholder '{{{}}}'", key))?;
+                    rendered.push_str(value);
+                }
+            }
+        }
+        Ok(rendered)
+    }
+}
+
+impl<'de> Deserialize<'de> for FormatTemplate {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct FormatTemplateVisitor;
+
+        impl<'de> Visitor<'de> for FormatTemplateVisitor {
+            type Value = FormatTemplate;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a format string, e.g. \"{volume}%\"")
+            }
+
+            fn visit_str<E>(self, s: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                FormatTemplate::from_string(s).map_err(DeError::custom)
+            }
+        }
+
+        deserializer.deserialize_str(FormatTemplateVisitor)
+    }
+}
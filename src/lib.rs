@@ -0,0 +1,8 @@
+pub mod blocks;
+pub mod config;
+pub mod errors;
+pub mod formatting;
+pub mod input;
+pub mod scheduler;
+pub mod widget;
+pub mod widgets;